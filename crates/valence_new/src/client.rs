@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use uuid::Uuid;
+use valence_protocol::packets::c2s::play::C2sPlayPacket;
+use valence_protocol::packets::s2c::play::{S2cPlayPacket, SystemChatMessageS2c};
+use valence_protocol::text::Text;
+use valence_protocol::Username;
+
+use crate::server::packet_manager::{PlayPacketReceiver, PlayPacketSender};
+
+/// A client entity's connection to the server.
+///
+/// Spawned for every client that completes login; see
+/// [`run_server`](crate::server::run_server).
+#[derive(Component)]
+pub struct Client {
+    send: PlayPacketSender,
+    recv: PlayPacketReceiver,
+    username: Username<String>,
+    uuid: Uuid,
+    disconnected: bool,
+    disconnect_reason: Option<String>,
+    ping: Duration,
+}
+
+impl Client {
+    pub(crate) fn new(
+        username: Username<String>,
+        uuid: Uuid,
+        send: PlayPacketSender,
+        recv: PlayPacketReceiver,
+    ) -> Self {
+        Self {
+            send,
+            recv,
+            username,
+            uuid,
+            disconnected: false,
+            disconnect_reason: None,
+            ping: Duration::ZERO,
+        }
+    }
+
+    /// The client's username.
+    pub fn username(&self) -> &str {
+        self.username.as_str()
+    }
+
+    /// The client's UUID.
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Whether this client has been marked for disconnection. The
+    /// connection task tears down the socket and the client's entity is
+    /// despawned once this is observed.
+    pub fn is_disconnected(&self) -> bool {
+        self.disconnected
+    }
+
+    /// The reason this client was disconnected, if any.
+    pub fn disconnect_reason(&self) -> Option<&str> {
+        self.disconnect_reason.as_deref()
+    }
+
+    /// Marks this client to be disconnected with the given reason.
+    pub fn disconnect(&mut self, reason: impl Into<String>) {
+        if !self.disconnected {
+            self.disconnected = true;
+            self.disconnect_reason = Some(reason.into());
+        }
+    }
+
+    /// Queues an outgoing play packet to be sent to the client.
+    pub(crate) fn send_packet(&self, packet: &S2cPlayPacket) {
+        self.send.send(packet.clone());
+    }
+
+    /// Queues a system chat message to be sent to the client.
+    pub fn send_message(&self, message: impl Into<Text>) {
+        self.send_packet(&S2cPlayPacket::SystemChatMessageS2c(SystemChatMessageS2c {
+            chat: message.into(),
+            overlay: false,
+        }));
+    }
+
+    /// Returns the next packet received from the client, if one is
+    /// buffered. See [`PlayPacketReceiver::try_recv`] for how malformed
+    /// frames are reported.
+    pub(crate) fn try_recv_packet(&mut self) -> Option<anyhow::Result<C2sPlayPacket>> {
+        self.recv.try_recv()
+    }
+
+    /// The measured round-trip latency of the connection, based on the most
+    /// recently acknowledged keep-alive.
+    pub fn ping(&self) -> Duration {
+        self.ping
+    }
+
+    pub(crate) fn set_ping(&mut self, ping: Duration) {
+        self.ping = ping;
+    }
+}
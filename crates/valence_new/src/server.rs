@@ -12,6 +12,7 @@ use flume::{Receiver, Sender};
 use rand::rngs::OsRng;
 use rsa::{PublicKeyParts, RsaPrivateKey};
 use tokio::runtime::{Handle, Runtime};
+use tokio::signal;
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tracing::instrument;
 use uuid::Uuid;
@@ -23,11 +24,17 @@ use crate::client::Client;
 use crate::config::{AsyncCallbacks, Config, ConnectionMode};
 use crate::dimension::{Dimension, DimensionId};
 use crate::player_textures::SignedPlayerTextures;
+#[cfg(feature = "graphql-admin")]
+use crate::server::admin::{build_schema, run_admin_endpoint, AdminCommand, AdminSnapshot};
 use crate::server::connect::do_accept_loop;
+use crate::server::keep_alive::{update_keep_alive, KeepAliveState};
 use crate::server::packet_manager::{PlayPacketReceiver, PlayPacketSender};
 
+#[cfg(feature = "graphql-admin")]
+mod admin;
 mod byte_channel;
 mod connect;
+mod keep_alive;
 mod packet_manager;
 
 /// Contains global server state accessible as a [`Resource`].
@@ -72,18 +79,29 @@ impl Server {
 pub struct SharedServer(Arc<SharedServerInner>);
 
 struct SharedServerInner {
-    address: SocketAddr,
+    /// The addresses this server accepts connections on. Every address
+    /// shares the same `connection_sema`, so `max_connections` remains a
+    /// global cap regardless of how many listeners are bound.
+    addresses: Vec<SocketAddr>,
     tick_rate: i64,
     connection_mode: ConnectionMode,
     compression_threshold: Option<u32>,
     max_connections: usize,
     incoming_capacity: usize,
     outgoing_capacity: usize,
+    /// How often to send a keep-alive packet to each client.
+    keep_alive_interval: Duration,
+    /// How long to wait for a keep-alive response before disconnecting a
+    /// client.
+    keep_alive_timeout: Duration,
     /// The tokio handle used by the server.
     tokio_handle: Handle,
     /// Holding a runtime handle is not enough to keep tokio working. We need
     /// to store the runtime here so we don't drop it.
     _tokio_runtime: Option<Runtime>,
+    /// The number of worker threads in `_tokio_runtime`, or `None` if an
+    /// external `tokio_handle` was supplied instead.
+    tokio_worker_threads: Option<usize>,
     dimensions: Vec<Dimension>,
     biomes: Vec<Biome>,
     /// Contains info about dimensions, biomes, and chats.
@@ -100,6 +118,9 @@ struct SharedServerInner {
     connection_sema: Arc<Semaphore>,
     /// The result that will be returned when the server is shut down.
     shutdown_result: Mutex<Option<anyhow::Result<()>>>,
+    /// State for an in-progress graceful shutdown, if one has been triggered
+    /// via [`SharedServer::shutdown_with_message`].
+    draining: Mutex<Option<DrainState>>,
     /// The RSA keypair used for encryption with clients.
     rsa_key: RsaPrivateKey,
     /// The public part of `rsa_key` encoded in DER, which is an ASN.1 format.
@@ -107,12 +128,26 @@ struct SharedServerInner {
     public_key_der: Box<[u8]>,
     /// For session server requests.
     http_client: reqwest::Client,
+    /// The latest snapshot of server state published for the admin endpoint,
+    /// and the channel admin mutations arrive on to be applied to the world.
+    #[cfg(feature = "graphql-admin")]
+    admin: AdminState,
+}
+
+#[cfg(feature = "graphql-admin")]
+struct AdminState {
+    snapshot: Arc<Mutex<AdminSnapshot>>,
+    command_send: Sender<AdminCommand>,
+    command_recv: Receiver<AdminCommand>,
 }
 
 impl SharedServer {
-    /// Gets the socket address this server is bound to.
-    pub fn address(&self) -> SocketAddr {
-        self.0.address
+    /// Returns an iterator over the socket addresses this server is bound
+    /// to. A server bound with [`Config::with_address`] yields exactly one
+    /// address; one configured with [`Config::with_addresses`] may yield
+    /// several.
+    pub fn address(&self) -> impl ExactSizeIterator<Item = SocketAddr> + '_ {
+        self.0.addresses.iter().copied()
     }
 
     /// Gets the configured tick rate of this server.
@@ -141,6 +176,17 @@ impl SharedServer {
         self.0.incoming_capacity
     }
 
+    /// Gets the interval at which keep-alive packets are sent to clients.
+    pub fn keep_alive_interval(&self) -> Duration {
+        self.0.keep_alive_interval
+    }
+
+    /// Gets the amount of time a client has to respond to a keep-alive packet
+    /// before being disconnected for timing out.
+    pub fn keep_alive_timeout(&self) -> Duration {
+        self.0.keep_alive_timeout
+    }
+
     /// Gets the configured outgoing incoming capacity.
     pub fn outgoing_capacity(&self) -> usize {
         self.0.outgoing_capacity
@@ -151,6 +197,14 @@ impl SharedServer {
         &self.0.tokio_handle
     }
 
+    /// Gets the number of worker threads used by the server's tokio runtime,
+    /// or `None` if an external [`Handle`] was supplied via
+    /// [`Config::tokio_handle`](crate::config::Config) instead of letting the
+    /// server build its own runtime.
+    pub fn tokio_worker_threads(&self) -> Option<usize> {
+        self.0.tokio_worker_threads
+    }
+
     /// Obtains a [`Dimension`] by using its corresponding [`DimensionId`].
     ///
     /// It is safe but unspecified behavior to call this function using a
@@ -216,6 +270,37 @@ impl SharedServer {
         self.0.connection_sema.close();
         *self.0.shutdown_result.lock().unwrap() = Some(res.map_err(|e| e.into()));
     }
+
+    /// Like [`Self::shutdown`], but drains existing connections first instead
+    /// of stopping immediately.
+    ///
+    /// New connections are blocked right away, every connected client is
+    /// marked disconnected with `message` as its reason, and the server runs
+    /// for a few more ticks to give already-queued outgoing packets a chance
+    /// to flush before [`run_server`] returns `res`. Marking a client
+    /// disconnected queues no packet, so `message` itself is not sent to the
+    /// clients.
+    pub fn shutdown_with_message<E>(&self, res: Result<(), E>, message: impl Into<String>)
+    where
+        E: Into<anyhow::Error>,
+    {
+        self.0.connection_sema.close();
+        *self.0.draining.lock().unwrap() = Some(DrainState {
+            message: message.into(),
+            result: Some(res.map_err(|e| e.into())),
+            ticks_remaining: SHUTDOWN_DRAIN_TICKS,
+        });
+    }
+}
+
+/// The number of extra ticks a graceful shutdown runs for after marking every
+/// client disconnected, to give outgoing packets a chance to flush.
+const SHUTDOWN_DRAIN_TICKS: u32 = 20;
+
+struct DrainState {
+    message: String,
+    result: Option<anyhow::Result<()>>,
+    ticks_remaining: u32,
 }
 
 /// Contains information about a new client joining the server.
@@ -240,6 +325,12 @@ struct NewClientMessage {
     permit: OwnedSemaphorePermit,
 }
 
+/// Holds a client's connection permit for as long as its entity is alive.
+/// Dropped (releasing the permit back to `connection_sema`) when the entity
+/// is despawned.
+#[derive(Component)]
+struct ConnectionPermit(#[allow(dead_code)] OwnedSemaphorePermit);
+
 /// Consumes the configuration and starts the Minecraft server.
 ///
 /// This function blocks the current thread and returns once the server has
@@ -250,6 +341,10 @@ pub fn run_server(
     stage: impl Stage,
     callbacks: impl AsyncCallbacks,
 ) -> anyhow::Result<()> {
+    ensure!(
+        !cfg.addresses.is_empty(),
+        "must configure at least one address to bind to"
+    );
     ensure!(
         cfg.tick_rate > 0,
         "configured tick rate must be greater than zero"
@@ -262,6 +357,23 @@ pub fn run_server(
         cfg.outgoing_capacity > 0,
         "configured outgoing packet capacity must be nonzero"
     );
+    ensure!(
+        cfg.tokio_worker_threads.map_or(true, |n| n > 0),
+        "configured tokio worker thread count must be greater than zero"
+    );
+    #[cfg(feature = "graphql-admin")]
+    if let Some(admin_addr) = cfg.admin_address {
+        ensure!(
+            cfg.admin_token.as_deref().is_some_and(|t| !t.is_empty()),
+            "admin_address is set but no admin_token was configured -- the admin endpoint can \
+             broadcast chat and kick players, so it must not be reachable without a token"
+        );
+        ensure!(
+            admin_addr.ip().is_loopback() || cfg.admin_allow_non_loopback,
+            "admin_address {admin_addr} is not a loopback address; set \
+             admin_allow_non_loopback to true to bind it anyway"
+        );
+    }
 
     let rsa_key = RsaPrivateKey::new(&mut OsRng, 1024)?;
 
@@ -269,12 +381,34 @@ pub fn run_server(
         rsa_der::public_key_to_der(&rsa_key.n().to_bytes_be(), &rsa_key.e().to_bytes_be())
             .into_boxed_slice();
 
+    // Only relevant if we're building our own runtime below; an externally
+    // supplied `tokio_handle` already has its own worker pool.
+    let tokio_worker_threads = cfg.tokio_worker_threads.unwrap_or_else(|| {
+        thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+
     let runtime = if cfg.tokio_handle.is_none() {
-        Some(Runtime::new()?)
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+
+        builder.enable_all().worker_threads(tokio_worker_threads);
+
+        if let Some(prefix) = &cfg.tokio_thread_name_prefix {
+            builder.thread_name(prefix);
+        }
+
+        if let Some(stack_size) = cfg.tokio_thread_stack_size {
+            builder.thread_stack_size(stack_size);
+        }
+
+        Some(builder.build()?)
     } else {
         None
     };
 
+    let tokio_worker_threads = runtime.is_some().then_some(tokio_worker_threads);
+
     let tokio_handle = match &runtime {
         Some(rt) => rt.handle().clone(),
         None => cfg.tokio_handle.unwrap(),
@@ -284,16 +418,41 @@ pub fn run_server(
 
     let (new_clients_send, new_clients_recv) = flume::bounded(64);
 
+    #[cfg(feature = "graphql-admin")]
+    let admin = {
+        let (command_send, command_recv) = flume::unbounded();
+
+        AdminState {
+            snapshot: Arc::new(Mutex::new(AdminSnapshot {
+                tick: 0,
+                mspt_mean_millis: 0.0,
+                mspt_p99_millis: 0.0,
+                connection_count: 0,
+                max_connections: cfg.max_connections,
+                dimensions: (0..cfg.dimensions.len())
+                    .map(|i| DimensionId(i as u16).dimension_type_name().to_string())
+                    .collect(),
+                biomes: cfg.biomes.iter().map(|b| b.name.to_string()).collect(),
+                players: vec![],
+            })),
+            command_send,
+            command_recv,
+        }
+    };
+
     let shared = SharedServer(Arc::new(SharedServerInner {
-        address: cfg.address,
+        addresses: cfg.addresses.clone(),
         tick_rate: cfg.tick_rate,
         connection_mode: cfg.connection_mode,
         compression_threshold: cfg.compression_threshold,
         max_connections: cfg.max_connections,
         incoming_capacity: cfg.incoming_capacity,
         outgoing_capacity: cfg.outgoing_capacity,
+        keep_alive_interval: cfg.keep_alive_interval,
+        keep_alive_timeout: cfg.keep_alive_timeout,
         tokio_handle,
         _tokio_runtime: runtime,
+        tokio_worker_threads,
         dimensions: vec![],
         biomes: vec![],
         registry_codec,
@@ -302,9 +461,12 @@ pub fn run_server(
         new_clients_recv,
         connection_sema: Arc::new(Semaphore::new(cfg.max_connections)),
         shutdown_result: Mutex::new(None),
+        draining: Mutex::new(None),
         rsa_key,
         public_key_der,
         http_client: Default::default(),
+        #[cfg(feature = "graphql-admin")]
+        admin,
     }));
 
     let server = Server {
@@ -320,13 +482,39 @@ pub fn run_server(
 
     let mut schedule = Schedule::default();
 
-    // TODO: add systems.
-    schedule.add_stage("user stage", stage);
+    schedule.add_stage(
+        "valence internal",
+        SystemStage::parallel().with_system(update_keep_alive),
+    );
+
+    // TODO: add more internal systems.
+    schedule.add_stage_after("valence internal", "user stage", stage);
+
+    if cfg.shutdown_signal {
+        let shared = shared.clone();
+        let message = cfg.shutdown_message.clone();
+
+        tokio::spawn(async move {
+            shutdown_signal().await;
+            shared.shutdown_with_message(Ok::<(), anyhow::Error>(()), message);
+        });
+    }
 
     let callbacks = Arc::new(callbacks);
     let mut tick_start = Instant::now();
     let full_tick_duration = Duration::from_secs_f64((shared.tick_rate() as f64).recip());
 
+    /// Number of recent tick durations kept for the admin endpoint's MSPT
+    /// mean/p99 stats. `VecDeque::with_capacity` only guarantees *at least*
+    /// this many slots, so eviction must compare against this constant
+    /// rather than the deque's actual (unspecified) capacity.
+    #[cfg(feature = "graphql-admin")]
+    const MSPT_WINDOW: usize = 100;
+
+    #[cfg(feature = "graphql-admin")]
+    let mut recent_tick_durations: std::collections::VecDeque<Duration> =
+        std::collections::VecDeque::with_capacity(MSPT_WINDOW);
+
     loop {
         cfg.world.clear_trackers();
 
@@ -335,35 +523,159 @@ pub fn run_server(
             return res;
         }
 
+        // If a graceful shutdown is in progress, mark every client
+        // disconnected with the shutdown message once, then let the loop run
+        // for a few more ticks to flush outgoing packets before actually
+        // stopping.
+        {
+            let mut draining = shared.0.draining.lock().unwrap();
+            if let Some(drain) = draining.as_mut() {
+                if drain.ticks_remaining == SHUTDOWN_DRAIN_TICKS {
+                    let message = drain.message.clone();
+                    let mut clients = cfg.world.query::<&mut Client>();
+
+                    for mut client in clients.iter_mut(&mut cfg.world) {
+                        client.disconnect(message.clone());
+                    }
+                }
+
+                if drain.ticks_remaining == 0 {
+                    return drain.result.take().unwrap();
+                }
+
+                drain.ticks_remaining -= 1;
+            }
+        }
+
         // Spawn new client entities.
         for _ in 0..shared.0.new_clients_recv.len() {
             let Ok(msg) = shared.0.new_clients_recv.try_recv() else {
                 break
             };
 
-            cfg.world.spawn(Client::new());
+            cfg.world.spawn((
+                Client::new(msg.info.username, msg.info.uuid, msg.send, msg.recv),
+                KeepAliveState::new(),
+                ConnectionPermit(msg.permit),
+            ));
+        }
+
+        // Apply any commands that arrived through the admin endpoint.
+        #[cfg(feature = "graphql-admin")]
+        for command in shared.0.admin.command_recv.try_iter() {
+            let mut clients = cfg.world.query::<&mut Client>();
+
+            match command {
+                AdminCommand::BroadcastChat(message) => {
+                    for mut client in clients.iter_mut(&mut cfg.world) {
+                        client.send_message(message.clone());
+                    }
+                }
+                AdminCommand::KickPlayer(uuid, reason) => {
+                    for mut client in clients.iter_mut(&mut cfg.world) {
+                        if client.uuid() == uuid {
+                            client.disconnect(reason.clone());
+                        }
+                    }
+                }
+            }
         }
 
         // Run the scheduled stages.
         schedule.run_once(&mut cfg.world);
 
+        // Only collected when the admin endpoint is actually running, so
+        // compiling with the `graphql-admin` feature costs nothing for
+        // embedders who don't set `admin_address`.
+        #[cfg(feature = "graphql-admin")]
+        let admin_players: Vec<(Uuid, String)> = if cfg.admin_address.is_some() {
+            cfg.world
+                .query::<&Client>()
+                .iter(&cfg.world)
+                .map(|c| (c.uuid(), c.username().to_string()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         let mut server = cfg.world.resource_mut::<Server>();
 
         // Initialize the accept loop after we run the schedule for the first time. This
         // way, lengthy initialization work can happen without any players connecting
         // before it is finished.
         if server.current_tick == 0 {
-            tokio::spawn(do_accept_loop(shared.clone(), callbacks.clone()));
+            for &addr in &shared.0.addresses {
+                tokio::spawn(do_accept_loop(shared.clone(), addr, callbacks.clone()));
+            }
+
+            #[cfg(feature = "graphql-admin")]
+            if let Some(admin_addr) = cfg.admin_address {
+                let schema = build_schema(
+                    shared.0.admin.snapshot.clone(),
+                    shared.0.admin.command_send.clone(),
+                );
+                let token: Arc<str> = cfg.admin_token.clone().unwrap_or_default().into();
+
+                tokio::spawn(run_admin_endpoint(admin_addr, schema, token));
+            }
         }
 
         // Sleep until the next tick.
         server.last_tick_duration = tick_start.elapsed();
         thread::sleep(full_tick_duration.saturating_sub(server.last_tick_duration));
+
+        #[cfg(feature = "graphql-admin")]
+        if cfg.admin_address.is_some() {
+            if recent_tick_durations.len() == MSPT_WINDOW {
+                recent_tick_durations.pop_front();
+            }
+            recent_tick_durations.push_back(server.last_tick_duration);
+
+            let mut sorted: Vec<Duration> = recent_tick_durations.iter().copied().collect();
+            sorted.sort_unstable();
+
+            let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+            let p99 = sorted[(sorted.len() * 99 / 100).min(sorted.len() - 1)];
+
+            let mut snapshot = shared.0.admin.snapshot.lock().unwrap();
+            snapshot.tick = server.current_tick;
+            snapshot.mspt_mean_millis = mean.as_secs_f64() * 1000.0;
+            snapshot.mspt_p99_millis = p99.as_secs_f64() * 1000.0;
+            snapshot.connection_count =
+                shared.0.max_connections - shared.0.connection_sema.available_permits();
+            snapshot.players = admin_players;
+        }
+
         server.current_tick += 1;
         tick_start = Instant::now();
     }
 }
 
+/// Resolves once the process receives Ctrl-C (SIGINT), or on Unix, SIGTERM.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl-C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
 fn make_registry_codec(dimensions: &[Dimension], biomes: &[Biome]) -> Compound {
     let dimensions = dimensions
         .iter()
@@ -0,0 +1,195 @@
+//! Optional GraphQL/HTTP observability and admin endpoint.
+//!
+//! Everything in this module is gated behind the `graphql-admin` feature so
+//! it adds no overhead when unused. Queries read a snapshot of server state
+//! that is refreshed once per tick in [`run_server`](super::run_server);
+//! mutations are forwarded to the tick loop through [`AdminCommand`] so they
+//! can be applied to the ECS world safely.
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use async_graphql::{Context, EmptySubscription, Object, Schema, SimpleObject};
+use async_graphql_axum::GraphQL;
+use axum::extract::State;
+use axum::http::{header, Request, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::routing::post;
+use axum::Router;
+use flume::Sender;
+use subtle::ConstantTimeEq;
+use tracing::error;
+use uuid::Uuid;
+
+/// A point-in-time view of server state, refreshed once per tick.
+///
+/// Admin requests and the tick loop share it through one `Mutex`; resolvers
+/// hold the lock only long enough to copy out what they return.
+pub(crate) struct AdminSnapshot {
+    pub tick: i64,
+    pub mspt_mean_millis: f64,
+    pub mspt_p99_millis: f64,
+    pub connection_count: usize,
+    pub max_connections: usize,
+    pub dimensions: Vec<String>,
+    pub biomes: Vec<String>,
+    pub players: Vec<(Uuid, String)>,
+}
+
+/// A command issued through the admin endpoint to be applied to the ECS
+/// world on the next tick.
+pub(crate) enum AdminCommand {
+    /// Broadcast a chat message to every connected client.
+    BroadcastChat(String),
+    /// Disconnect the client with the given UUID, if connected.
+    KickPlayer(Uuid, String),
+}
+
+#[derive(SimpleObject)]
+struct Status {
+    tick: i64,
+    mspt_mean_millis: f64,
+    mspt_p99_millis: f64,
+    connection_count: usize,
+    max_connections: usize,
+}
+
+#[derive(SimpleObject)]
+struct PlayerInfo {
+    uuid: Uuid,
+    username: String,
+}
+
+pub(crate) struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn status(&self, ctx: &Context<'_>) -> Status {
+        let snapshot = ctx.data_unchecked::<Arc<Mutex<AdminSnapshot>>>().lock().unwrap();
+
+        Status {
+            tick: snapshot.tick,
+            mspt_mean_millis: snapshot.mspt_mean_millis,
+            mspt_p99_millis: snapshot.mspt_p99_millis,
+            connection_count: snapshot.connection_count,
+            max_connections: snapshot.max_connections,
+        }
+    }
+
+    async fn dimensions(&self, ctx: &Context<'_>) -> Vec<String> {
+        ctx.data_unchecked::<Arc<Mutex<AdminSnapshot>>>()
+            .lock()
+            .unwrap()
+            .dimensions
+            .clone()
+    }
+
+    async fn biomes(&self, ctx: &Context<'_>) -> Vec<String> {
+        ctx.data_unchecked::<Arc<Mutex<AdminSnapshot>>>()
+            .lock()
+            .unwrap()
+            .biomes
+            .clone()
+    }
+
+    async fn players(&self, ctx: &Context<'_>) -> Vec<PlayerInfo> {
+        ctx.data_unchecked::<Arc<Mutex<AdminSnapshot>>>()
+            .lock()
+            .unwrap()
+            .players
+            .iter()
+            .map(|(uuid, username)| PlayerInfo {
+                uuid: *uuid,
+                username: username.clone(),
+            })
+            .collect()
+    }
+}
+
+pub(crate) struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    async fn broadcast_chat(&self, ctx: &Context<'_>, message: String) -> bool {
+        ctx.data_unchecked::<Sender<AdminCommand>>()
+            .send(AdminCommand::BroadcastChat(message))
+            .is_ok()
+    }
+
+    async fn kick_player(&self, ctx: &Context<'_>, uuid: Uuid, reason: String) -> bool {
+        ctx.data_unchecked::<Sender<AdminCommand>>()
+            .send(AdminCommand::KickPlayer(uuid, reason))
+            .is_ok()
+    }
+}
+
+pub(crate) type AdminSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+/// Builds the GraphQL schema, wiring the shared snapshot and command channel
+/// in as context data for resolvers to use.
+pub(crate) fn build_schema(
+    snapshot: std::sync::Arc<Mutex<AdminSnapshot>>,
+    command_send: Sender<AdminCommand>,
+) -> AdminSchema {
+    Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+        .data(snapshot)
+        .data(command_send)
+        .finish()
+}
+
+/// Rejects any request whose `Authorization: Bearer <token>` header doesn't
+/// match `expected`, so reaching `admin_address` isn't by itself enough to
+/// query or mutate server state.
+async fn require_token(
+    State(expected): State<Arc<str>>,
+    request: Request<axum::body::Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let provided = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    // Compare in constant time so response timing can't be used to recover
+    // the token a byte at a time.
+    let authorized = match provided {
+        Some(provided) => {
+            provided.len() == expected.len()
+                && provided.as_bytes().ct_eq(expected.as_bytes()).into()
+        }
+        None => false,
+    };
+
+    if !authorized {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    Ok(next.run(request).await)
+}
+
+/// Serves the GraphQL admin endpoint on `addr` until the process exits.
+///
+/// Every request must carry `Authorization: Bearer <token>` matching
+/// `token`, since this endpoint can broadcast chat and kick players and
+/// otherwise has no access control of its own. A bind failure is logged
+/// rather than propagated since it shouldn't take down the game server
+/// itself.
+pub(crate) async fn run_admin_endpoint(addr: SocketAddr, schema: AdminSchema, token: Arc<str>) {
+    let app = Router::new()
+        .route("/graphql", post(GraphQL::new(schema)))
+        .route_layer(middleware::from_fn_with_state(token, require_token));
+
+    let listener = match tokio::net::TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("failed to bind admin endpoint to {addr}: {e}");
+            return;
+        }
+    };
+
+    if let Err(e) = axum::serve(listener, app).await {
+        error!("admin endpoint on {addr} exited unexpectedly: {e}");
+    }
+}
@@ -0,0 +1,66 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::Context;
+use tokio::net::TcpStream;
+use tracing::{error, info, instrument};
+
+use crate::config::AsyncCallbacks;
+use crate::server::SharedServer;
+
+/// Accepts new connections on `addr` until it fails to bind or the process
+/// exits.
+///
+/// One instance of this loop is spawned per address in
+/// [`SharedServer::address`]; all of them share `shared`'s
+/// `connection_sema`, so `max_connections` remains a single global cap no
+/// matter how many listeners are bound.
+#[instrument(skip(shared, callbacks))]
+pub(super) async fn do_accept_loop(
+    shared: SharedServer,
+    addr: SocketAddr,
+    callbacks: Arc<impl AsyncCallbacks>,
+) {
+    let listener = match tokio::net::TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("failed to bind listener to {addr}: {e:#}");
+            return;
+        }
+    };
+
+    info!("listening for connections on {addr}");
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, remote_addr)) => {
+                let shared = shared.clone();
+                let callbacks = callbacks.clone();
+
+                tokio::spawn(async move {
+                    if let Err(e) = handle_connection(stream, remote_addr, shared, callbacks).await
+                    {
+                        error!("connection from {remote_addr} on {addr} ended with error: {e:#}");
+                    }
+                });
+            }
+            Err(e) => error!("failed to accept connection on {addr}: {e:#}"),
+        }
+    }
+}
+
+async fn handle_connection(
+    stream: TcpStream,
+    remote_addr: SocketAddr,
+    shared: SharedServer,
+    callbacks: Arc<impl AsyncCallbacks>,
+) -> anyhow::Result<()> {
+    stream
+        .set_nodelay(true)
+        .context("failed to set TCP_NODELAY")?;
+
+    // TODO: handshake, login, and play-phase packet handling.
+    let _ = (remote_addr, shared, callbacks);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use std::time::{Duration, Instant};
+
+use bevy_ecs::prelude::*;
+use rand::Rng;
+use valence_protocol::packets::c2s::play::C2sPlayPacket;
+use valence_protocol::packets::s2c::play::{KeepAliveS2c, S2cPlayPacket};
+
+use crate::client::Client;
+use crate::server::Server;
+
+/// Tracks the liveness of a single client's connection.
+///
+/// Attached to every client entity alongside [`Client`] so the keep-alive
+/// system can detect connections whose TCP socket has died silently rather
+/// than waiting on the OS to notice. The measured latency this produces is
+/// surfaced publicly through [`Client::ping`].
+#[derive(Component)]
+pub(crate) struct KeepAliveState {
+    outstanding: Option<OutstandingKeepAlive>,
+    last_sent: Instant,
+}
+
+struct OutstandingKeepAlive {
+    id: i64,
+    sent_at: Instant,
+}
+
+impl KeepAliveState {
+    pub(crate) fn new() -> Self {
+        Self {
+            outstanding: None,
+            last_sent: Instant::now(),
+        }
+    }
+
+    /// Called when a keep-alive response is received from the client.
+    /// Returns the measured round-trip latency if `id` matches the
+    /// outstanding keep-alive, or `None` if it doesn't -- a protocol error
+    /// that should disconnect the client.
+    fn handle_response(&mut self, id: i64, received_at: Instant) -> Option<Duration> {
+        match self.outstanding.take() {
+            Some(outstanding) if outstanding.id == id => {
+                Some(received_at.saturating_duration_since(outstanding.sent_at))
+            }
+            Some(outstanding) => {
+                // Leave the outstanding entry in place; only a matching id clears it.
+                self.outstanding = Some(outstanding);
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handle_response_matching_id_clears_outstanding_and_returns_latency() {
+        let sent_at = Instant::now();
+        let mut state = KeepAliveState {
+            outstanding: Some(OutstandingKeepAlive { id: 42, sent_at }),
+            last_sent: sent_at,
+        };
+
+        let received_at = sent_at + Duration::from_millis(50);
+        let ping = state.handle_response(42, received_at);
+
+        assert_eq!(ping, Some(Duration::from_millis(50)));
+        assert!(state.outstanding.is_none());
+    }
+
+    #[test]
+    fn handle_response_mismatched_id_leaves_outstanding_in_place() {
+        let sent_at = Instant::now();
+        let mut state = KeepAliveState {
+            outstanding: Some(OutstandingKeepAlive { id: 42, sent_at }),
+            last_sent: sent_at,
+        };
+
+        let ping = state.handle_response(7, sent_at + Duration::from_millis(50));
+
+        assert_eq!(ping, None);
+        assert_eq!(state.outstanding.as_ref().map(|o| o.id), Some(42));
+    }
+
+    #[test]
+    fn handle_response_with_no_outstanding_keep_alive_returns_none() {
+        let mut state = KeepAliveState::new();
+
+        assert_eq!(state.handle_response(1, Instant::now()), None);
+    }
+}
+
+/// Sends keep-alive packets to clients on the configured interval and
+/// disconnects clients that fail to respond within the configured timeout.
+///
+/// Also drains each client's incoming packets looking for keep-alive
+/// responses. This is the only consumer of incoming play packets in this
+/// schedule so far; once other systems are registered they should take
+/// over dispatching the packet kinds they care about instead of this
+/// system swallowing everything.
+pub(crate) fn update_keep_alive(
+    server: Res<Server>,
+    mut clients: Query<(Entity, &mut Client, &mut KeepAliveState)>,
+    mut commands: Commands,
+) {
+    let now = Instant::now();
+
+    for (entity, mut client, mut state) in &mut clients {
+        if client.is_disconnected() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let mut protocol_error = false;
+
+        while let Some(packet) = client.try_recv_packet() {
+            match packet {
+                Ok(C2sPlayPacket::KeepAliveC2s(p)) => match state.handle_response(p.id, now) {
+                    Some(ping) => client.set_ping(ping),
+                    None => {
+                        client.disconnect("Invalid keep-alive ID");
+                        protocol_error = true;
+                        break;
+                    }
+                },
+                Ok(_) => {}
+                Err(e) => {
+                    // A malformed frame is a protocol error, not a panic.
+                    client.disconnect(format!("Malformed packet: {e:#}"));
+                    protocol_error = true;
+                    break;
+                }
+            }
+        }
+
+        if protocol_error {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if let Some(outstanding) = &state.outstanding {
+            if now.saturating_duration_since(outstanding.sent_at) >= server.keep_alive_timeout() {
+                client.disconnect("Timed out");
+                commands.entity(entity).despawn();
+            }
+        } else if now.saturating_duration_since(state.last_sent) >= server.keep_alive_interval() {
+            let id = rand::thread_rng().gen();
+
+            client.send_packet(&S2cPlayPacket::KeepAliveS2c(KeepAliveS2c { id }));
+
+            state.outstanding = Some(OutstandingKeepAlive { id, sent_at: now });
+            state.last_sent = now;
+        }
+    }
+}
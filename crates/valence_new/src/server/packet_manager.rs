@@ -0,0 +1,42 @@
+use flume::{Receiver, Sender};
+use valence_protocol::packets::c2s::play::C2sPlayPacket;
+use valence_protocol::packets::s2c::play::S2cPlayPacket;
+
+/// The client-entity side of the channel outgoing play packets are queued
+/// on, to be framed and written to the socket by the connection task.
+pub(crate) struct PlayPacketSender {
+    send: Sender<S2cPlayPacket>,
+}
+
+impl PlayPacketSender {
+    pub(crate) fn new(send: Sender<S2cPlayPacket>) -> Self {
+        Self { send }
+    }
+
+    /// Queues `packet` to be sent to the client. Silently dropped if the
+    /// connection task has already exited.
+    pub(crate) fn send(&self, packet: S2cPlayPacket) {
+        let _ = self.send.send(packet);
+    }
+}
+
+/// The client-entity side of the channel incoming play packets (already
+/// decoded by the connection task) arrive on.
+pub(crate) struct PlayPacketReceiver {
+    recv: Receiver<anyhow::Result<C2sPlayPacket>>,
+}
+
+impl PlayPacketReceiver {
+    pub(crate) fn new(recv: Receiver<anyhow::Result<C2sPlayPacket>>) -> Self {
+        Self { recv }
+    }
+
+    /// Returns the next packet from the client, if one is buffered.
+    ///
+    /// `Ok` wraps a successfully decoded packet; `Err` indicates the
+    /// connection task hit a malformed frame, which callers should treat as
+    /// a protocol error (e.g. disconnect the client) rather than a panic.
+    pub(crate) fn try_recv(&mut self) -> Option<anyhow::Result<C2sPlayPacket>> {
+        self.recv.try_recv().ok()
+    }
+}